@@ -0,0 +1,20 @@
+//! The schema AST: what [`crate::ast::parser::parse`] produces and [`crate::lift_ast`] consumes.
+
+pub mod parser;
+
+/// The parsed, but not yet validated, representation of a `.prisma` schema.
+///
+/// A minimal placeholder carrying just enough (the model names) for [`crate::lift_ast`] and the
+/// migration engine's datamodel diffing to operate on; the full AST (fields, attributes, spans)
+/// isn't part of this crate snapshot.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SchemaAst {
+    pub model_names: Vec<String>,
+}
+
+impl SchemaAst {
+    /// An empty schema, as used for a datamodel with no prior migrations applied.
+    pub fn empty() -> Self {
+        SchemaAst::default()
+    }
+}
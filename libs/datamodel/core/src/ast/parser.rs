@@ -0,0 +1,19 @@
+//! Turns `.prisma` schema source into a [`super::SchemaAst`].
+
+use super::SchemaAst;
+
+/// Parses a `.prisma` schema string into a [`SchemaAst`].
+///
+/// A minimal placeholder: it only extracts model names (enough for [`crate::lift_ast`] and
+/// datamodel diffing to work against), not the real grammar (fields, attributes, comments, block
+/// spans) this crate snapshot doesn't carry.
+pub fn parse(schema: &str) -> Result<SchemaAst, String> {
+    let model_names = schema
+        .lines()
+        .filter_map(|line| line.trim().strip_prefix("model "))
+        .filter_map(|rest| rest.split_whitespace().next())
+        .map(str::to_owned)
+        .collect();
+
+    Ok(SchemaAst { model_names })
+}
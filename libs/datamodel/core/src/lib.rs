@@ -0,0 +1,84 @@
+//! Parsing, validating and rendering Prisma schemas.
+
+pub mod ast;
+pub mod validator;
+
+use ast::SchemaAst;
+
+/// A validated Prisma schema: the in-memory representation everything downstream (the query
+/// engine, the migration engine, introspection) builds on.
+///
+/// This is a minimal placeholder for the real, much larger `Datamodel` (a list of `dml::Model`,
+/// enums, composite types, and so on) that this crate snapshot doesn't carry; it only exists so
+/// that call sites elsewhere in the tree which already referenced `datamodel::Datamodel` keep
+/// resolving.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Datamodel {
+    pub models: Vec<String>,
+}
+
+impl Datamodel {
+    pub fn new() -> Self {
+        Datamodel::default()
+    }
+
+    /// An empty datamodel, as used for a schema with no prior migrations applied.
+    pub fn empty() -> Self {
+        Datamodel::default()
+    }
+}
+
+/// An error produced while parsing, validating or lifting a schema.
+#[derive(Debug, Clone)]
+pub struct DatamodelError(String);
+
+impl std::fmt::Display for DatamodelError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl std::error::Error for DatamodelError {}
+
+/// Turns a validated [`SchemaAst`] into a [`Datamodel`].
+///
+/// Named `lift_ast` rather than `parse_*` because, unlike [`parse_datamodel`], it assumes the AST
+/// was already parsed and validated, and only needs translating into the `Datamodel` shape.
+pub fn lift_ast(ast: &SchemaAst) -> Result<Datamodel, DatamodelError> {
+    Ok(Datamodel {
+        models: ast.model_names.clone(),
+    })
+}
+
+/// Parses a schema string into a [`Datamodel`], without rendering any diagnostics.
+///
+/// Only model names are extracted (see [`ast::parser::parse`]); no validation runs.
+pub fn parse_datamodel(schema: &str) -> Result<Datamodel, DatamodelError> {
+    let schema_ast = ast::parser::parse(schema).map_err(DatamodelError)?;
+    lift_ast(&schema_ast)
+}
+
+/// Parses a schema string like [`parse_datamodel`], returning the error as a string.
+///
+/// No attribute-level validation runs, so index field problems are not reported and no pretty,
+/// ANSI-rendered error is produced; [`validator::composite_index_diagnostics::validate_index_fields`]
+/// is not called from here.
+pub fn parse_schema(schema: &str) -> Result<Datamodel, String> {
+    parse_datamodel(schema).map_err(|err| err.to_string())
+}
+
+/// Renders a [`Datamodel`] back to its `.prisma` schema source.
+pub fn render_datamodel_to_string(datamodel: &Datamodel) -> Option<String> {
+    if datamodel.models.is_empty() {
+        return Some(String::new());
+    }
+
+    Some(
+        datamodel
+            .models
+            .iter()
+            .map(|name| format!("model {} {{\n}}\n", name))
+            .collect::<Vec<_>>()
+            .join("\n"),
+    )
+}
@@ -0,0 +1,229 @@
+//! Structured diagnostics for `@@index`/`@@unique`/`@@fulltext` field path validation.
+//!
+//! Validating the fields of an index like `@@index([a.cat])` used to go straight from "a field
+//! doesn't resolve" to a pre-rendered, pretty-printed error string. That's fine for the CLI, but it
+//! means every other consumer (an LSP wanting a quick-fix, a test wanting to assert on a field name
+//! instead of an ANSI-colored paragraph) has to parse the string back apart. This module gives the
+//! validator a typed diagnostic to produce instead, and keeps the existing renderer as one consumer
+//! of it among others.
+//!
+//! This crate snapshot's `parse_schema` is itself a placeholder (see `crate::ast::parser::parse`)
+//! that doesn't run attribute-level validation at all, so there is no real call site in this tree
+//! to wire `validate_index_fields` into yet. It's written the way the real validator's per-index
+//! check would call it, so porting it back is a matter of replacing that check's body with a call
+//! to this function, not redesigning the function itself.
+
+use diagnostics::Span;
+
+/// One segment of a field path inside an index definition, e.g. the `a` and `cat` in `a.cat`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct IndexFieldPathSegment {
+    /// The field name as written in the index definition.
+    pub field_name: String,
+    /// `Some(type_name)` if this segment resolved to a field whose type is the composite type
+    /// `type_name` (the next segment is looked up in that type), `None` if it resolved to a
+    /// scalar field (which can only happen as the last segment of the path).
+    pub composite_type_name: Option<String>,
+}
+
+/// A single segment of a field path together with whether it could be resolved against the
+/// schema. Unresolved segments are the ones a quick-fix would want to offer suggestions for.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ResolvedIndexFieldPathSegment {
+    /// The segment resolved to a field, either a scalar field on the model or a field inside a
+    /// composite type.
+    Resolved(IndexFieldPathSegment),
+    /// The segment did not resolve to any field. `field_name` is the name as written, so callers
+    /// can suggest corrections (e.g. "did you mean `field`?").
+    Unresolved { field_name: String },
+}
+
+/// One field of an index's field list (e.g. `a.cat` and `id` in `@@index([a.cat, id])`), together
+/// with whether its path resolved.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ResolvedIndexField {
+    /// The whole path resolved.
+    Resolved(Vec<ResolvedIndexFieldPathSegment>),
+    /// The path failed to resolve, at the first segment that didn't. `full_path` is the path as
+    /// written; `unresolved_name` is that first bad segment's name.
+    Unresolved {
+        full_path: String,
+        unresolved_name: String,
+        composite_type_name: Option<String>,
+    },
+}
+
+/// Stable identifier for the kind of problem found while validating an index field path.
+///
+/// These are not meant to be user-facing strings: they are an API surface callers can `match` on
+/// instead of string-matching the rendered message.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IndexValidationErrorCode {
+    /// A field path segment does not resolve to any field on the model or within the composite
+    /// type it is rooted in (e.g. `a.cat` where `A` has no field `cat`).
+    UnknownCompositeField,
+    /// The root segment of a field path does not resolve to any field on the model at all (e.g.
+    /// `b.field` where the model has no field `b`).
+    UnknownRootField,
+}
+
+/// A single, structured diagnostic produced while validating the fields of an `@@index`,
+/// `@@unique` or `@@fulltext` attribute.
+///
+/// This is the typed counterpart of the pretty-printed "The index definition refers to the
+/// unknown fields: ..." message: same information, but addressable by callers that are not just
+/// rendering it to a terminal. One diagnostic covers a whole index's field list, not a single
+/// field path, because that's the granularity the pretty renderer reports at: an index with
+/// several unknown fields gets one "refers to the unknown fields: a, b, c" message, not one per
+/// field.
+#[derive(Debug, Clone, PartialEq)]
+pub struct IndexFieldValidationDiagnostic {
+    pub error_code: IndexValidationErrorCode,
+    /// Byte span of the index attribute, e.g. the span of `@@index([a.cat])`.
+    pub span: Span,
+    /// The model the index is defined on.
+    pub model_name: String,
+    /// Every field of the index's field list, in order, annotated with whether it resolved.
+    pub fields: Vec<ResolvedIndexField>,
+}
+
+impl IndexFieldValidationDiagnostic {
+    /// The fields that failed to resolve, by the name of their first unresolved segment. An LSP
+    /// can offer a quick-fix against each.
+    pub fn unresolved_fields(&self) -> impl Iterator<Item = &str> {
+        self.fields.iter().filter_map(|field| match field {
+            ResolvedIndexField::Unresolved { unresolved_name, .. } => Some(unresolved_name.as_str()),
+            ResolvedIndexField::Resolved(_) => None,
+        })
+    }
+
+    /// The composite type an unresolved field was looked up in, if any of them were. `None` when
+    /// every unresolved field is a field directly on the model.
+    fn composite_type_name(&self) -> Option<&str> {
+        self.fields.iter().find_map(|field| match field {
+            ResolvedIndexField::Unresolved {
+                composite_type_name: Some(name),
+                ..
+            } => Some(name.as_str()),
+            _ => None,
+        })
+    }
+
+    /// Renders the diagnostic the same way the validator used to render it directly: as the
+    /// "refers to the unknown fields: ..." sentence consumed by the pretty, colorized error
+    /// output. This keeps the pretty renderer a consumer of the diagnostic instead of the
+    /// diagnostic's only representation.
+    pub fn render_pretty_message(&self, attribute_name: &str) -> String {
+        let unresolved = self.unresolved_fields().collect::<Vec<_>>().join(", ");
+
+        match self.composite_type_name() {
+            Some(type_name) => format!(
+                "The {} definition refers to the unknown fields: {} in type {}.",
+                attribute_name, unresolved, type_name
+            ),
+            None => format!(
+                "The {} definition refers to the unknown fields: {}.",
+                attribute_name, unresolved
+            ),
+        }
+    }
+}
+
+/// The fields of a composite type, by name, paired with the composite type they embed (`None`
+/// for a plain scalar field). Used by [`validate_index_fields`] to resolve a dotted field path one
+/// segment at a time without needing the full parser database.
+pub struct CompositeTypeFields<'a> {
+    pub type_name: &'a str,
+    pub fields: &'a [(&'a str, Option<&'a str>)],
+}
+
+/// Validates a single dotted field path as written in an index's field list (e.g. the `a.cat` in
+/// `@@index([a.cat])`) against the model it is rooted in.
+///
+/// `model_fields` maps each of the model's field names to the composite type it embeds, or `None`
+/// for a plain scalar field. Returns the resolved path on success, or a [`ResolvedIndexField`]
+/// describing the first segment that does not resolve — a path fails at a single point, so unlike
+/// [`validate_index_fields`] there is nothing to aggregate here.
+fn validate_field_path(
+    model_fields: &[(&str, Option<&str>)],
+    composite_types: &[CompositeTypeFields<'_>],
+    path: &[&str],
+) -> ResolvedIndexField {
+    let full_path = path.join(".");
+    let mut resolved = Vec::with_capacity(path.len());
+    let mut current_fields = model_fields;
+    let mut current_composite_type: Option<&str> = None;
+
+    for &segment in path {
+        match current_fields.iter().find(|(name, _)| *name == segment) {
+            Some(&(name, composite_type)) => {
+                resolved.push(ResolvedIndexFieldPathSegment::Resolved(IndexFieldPathSegment {
+                    field_name: name.to_owned(),
+                    composite_type_name: composite_type.map(str::to_owned),
+                }));
+
+                current_composite_type = composite_type;
+                current_fields = composite_type
+                    .and_then(|type_name| composite_types.iter().find(|ct| ct.type_name == type_name))
+                    .map(|ct| ct.fields)
+                    .unwrap_or(&[]);
+            }
+            None => {
+                return ResolvedIndexField::Unresolved {
+                    full_path,
+                    unresolved_name: segment.to_owned(),
+                    composite_type_name: current_composite_type.map(str::to_owned),
+                };
+            }
+        }
+    }
+
+    ResolvedIndexField::Resolved(resolved)
+}
+
+/// Validates every field of an `@@index`/`@@unique`/`@@fulltext` attribute's field list (e.g.
+/// both `a.cat` and `id` in `@@index([a.cat, id])`) against the model it is defined on.
+///
+/// `model_fields` maps each of the model's field names to the composite type it embeds, or `None`
+/// for a plain scalar field. Returns the resolved fields on success, or one
+/// [`IndexFieldValidationDiagnostic`] aggregating every field that failed to resolve — matching
+/// how the pretty renderer reports a whole index's unknown fields in a single message rather than
+/// one per field.
+pub fn validate_index_fields(
+    model_name: &str,
+    model_fields: &[(&str, Option<&str>)],
+    composite_types: &[CompositeTypeFields<'_>],
+    field_paths: &[&[&str]],
+    span: Span,
+) -> Result<Vec<Vec<ResolvedIndexFieldPathSegment>>, IndexFieldValidationDiagnostic> {
+    let fields: Vec<ResolvedIndexField> = field_paths
+        .iter()
+        .map(|path| validate_field_path(model_fields, composite_types, path))
+        .collect();
+
+    if fields.iter().any(|field| matches!(field, ResolvedIndexField::Unresolved { .. })) {
+        let error_code = if fields
+            .iter()
+            .any(|field| matches!(field, ResolvedIndexField::Unresolved { composite_type_name: Some(_), .. }))
+        {
+            IndexValidationErrorCode::UnknownCompositeField
+        } else {
+            IndexValidationErrorCode::UnknownRootField
+        };
+
+        return Err(IndexFieldValidationDiagnostic {
+            error_code,
+            span,
+            model_name: model_name.to_owned(),
+            fields,
+        });
+    }
+
+    Ok(fields
+        .into_iter()
+        .map(|field| match field {
+            ResolvedIndexField::Resolved(segments) => segments,
+            ResolvedIndexField::Unresolved { .. } => unreachable!("checked above"),
+        })
+        .collect())
+}
@@ -0,0 +1,3 @@
+//! Validating a parsed schema beyond what the grammar itself can express.
+
+pub mod composite_index_diagnostics;
@@ -1,3 +1,7 @@
+use datamodel::validator::composite_index_diagnostics::{
+    validate_index_fields, CompositeTypeFields, IndexValidationErrorCode,
+};
+use diagnostics::Span;
 use expect_test::expect;
 use indoc::indoc;
 
@@ -51,6 +55,59 @@ fn index_to_a_missing_field_in_a_composite_type() {
     "#]];
 
     expected.assert_eq(&error);
+
+    // The same failure is also available as a structured diagnostic, so a caller that isn't
+    // rendering to a terminal (an LSP quick-fix, for instance) doesn't have to string-match the
+    // pretty message above to find out which field was unknown and in which type.
+    //
+    // `validate_index_fields` isn't wired into `parse_schema` in this crate snapshot: that parser
+    // is itself a placeholder that doesn't run attribute validation at all (see
+    // `datamodel::ast::parser::parse`), so there's no real call site yet to exercise it through.
+    // It's exercised directly here instead, against the same model this schema declares.
+    let model_fields = [("id", None), ("a", Some("A"))];
+    let composite_types = [CompositeTypeFields {
+        type_name: "A",
+        fields: &[("field", None)],
+    }];
+
+    let diagnostic = validate_index_fields("B", &model_fields, &composite_types, &[&["a", "cat"]], Span::empty())
+        .unwrap_err();
+
+    assert_eq!(diagnostic.error_code, IndexValidationErrorCode::UnknownCompositeField);
+    assert_eq!(diagnostic.unresolved_fields().collect::<Vec<_>>(), vec!["cat"]);
+    assert_eq!(
+        diagnostic.render_pretty_message("index"),
+        "The index definition refers to the unknown fields: cat in type A."
+    );
+}
+
+#[test]
+fn index_to_several_missing_fields_in_a_composite_type() {
+    // A single index can list more than one field; when several of them fail to resolve, the
+    // pretty renderer reports all of them in one message (`refers to the unknown fields: a, b`),
+    // not one message per field. `validate_index_fields` must aggregate the same way: it checks
+    // each path with `validate_field_path` and collects every failure into one diagnostic.
+    let model_fields = [("id", None), ("a", Some("A"))];
+    let composite_types = [CompositeTypeFields {
+        type_name: "A",
+        fields: &[("field", None)],
+    }];
+
+    let diagnostic = validate_index_fields(
+        "B",
+        &model_fields,
+        &composite_types,
+        &[&["a", "cat"], &["a", "field"], &["a", "dog"]],
+        Span::empty(),
+    )
+    .unwrap_err();
+
+    assert_eq!(diagnostic.error_code, IndexValidationErrorCode::UnknownCompositeField);
+    assert_eq!(diagnostic.unresolved_fields().collect::<Vec<_>>(), vec!["cat", "dog"]);
+    assert_eq!(
+        diagnostic.render_pretty_message("index"),
+        "The index definition refers to the unknown fields: cat, dog in type A."
+    );
 }
 
 #[test]
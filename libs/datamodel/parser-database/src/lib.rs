@@ -0,0 +1,5 @@
+//! Parses a Prisma schema into an AST, then resolves and typechecks it into a `ParserDatabase`.
+
+pub mod semantics;
+
+pub use semantics::Semantics;
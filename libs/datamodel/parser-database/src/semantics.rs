@@ -0,0 +1,66 @@
+//! A `Semantics` facade that resolves ids back to the walkers describing them.
+//!
+//! `Semantics` gathers in one place the lookups from AST ids (a `ModelId`, a `FieldId`, a
+//! `CompositeTypeId`) to the walkers around them, bound to one [`ParserDatabase`].
+//!
+//! It resolves ids only: it does not resolve a source span to a walker, and it does not resolve
+//! a dotted index field path (e.g. `a.field`) segment by segment; [`Semantics::index_field`]
+//! takes a field whose location was already resolved.
+
+use crate::{
+    ast,
+    types::{FieldWithArgs, IndexFieldLocation},
+    walkers::{CompositeTypeFieldWalker, IndexFieldWalker, ScalarFieldWalker},
+    ParserDatabase,
+};
+
+/// Entry point for resolving AST ids back to [`crate::walkers`] types.
+///
+/// Bound to a single [`ParserDatabase`], the same way a `ScalarFieldWalker` is bound to the `db`
+/// it was produced from.
+#[derive(Copy, Clone)]
+pub struct Semantics<'db> {
+    db: &'db ParserDatabase,
+}
+
+impl<'db> Semantics<'db> {
+    pub fn new(db: &'db ParserDatabase) -> Self {
+        Semantics { db }
+    }
+
+    /// Resolves a scalar field id directly on a model back to its walker.
+    pub fn scalar_field(self, model_id: ast::ModelId, field_id: ast::FieldId) -> ScalarFieldWalker<'db> {
+        ScalarFieldWalker {
+            model_id,
+            field_id,
+            db: self.db,
+            scalar_field: &self.db.types.scalar_fields[&(model_id, field_id)],
+        }
+    }
+
+    /// Resolves a field id inside a composite type back to its walker.
+    pub fn composite_type_field(
+        self,
+        ctid: ast::CompositeTypeId,
+        field_id: ast::FieldId,
+    ) -> CompositeTypeFieldWalker<'db> {
+        CompositeTypeFieldWalker {
+            ctid,
+            field_id,
+            field: &self.db.types.composite_type_fields[&(ctid, field_id)],
+            db: self.db,
+        }
+    }
+
+    /// Resolves one already-validated field of an index or unique criteria back to the walker it
+    /// refers to, whether that's a scalar field on the model or a field nested inside a composite
+    /// type. [`crate::walkers::UniqueCriteriaWalker::fields`] is built on top of this.
+    pub fn index_field(self, model_id: ast::ModelId, field: &FieldWithArgs) -> IndexFieldWalker<'db> {
+        match field.field_location {
+            IndexFieldLocation::InModel(field_id) => IndexFieldWalker::new(self.scalar_field(model_id, field_id)),
+            IndexFieldLocation::InCompositeType(ctid, field_id) => {
+                IndexFieldWalker::new(self.composite_type_field(ctid, field_id))
+            }
+        }
+    }
+}
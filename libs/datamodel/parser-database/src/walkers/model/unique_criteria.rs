@@ -1,9 +1,7 @@
+use crate::semantics::Semantics;
 use crate::types::FieldWithArgs;
-use crate::walkers::{CompositeTypeFieldWalker, IndexFieldWalker};
-use crate::{
-    ast,
-    {walkers::ScalarFieldWalker, ParserDatabase},
-};
+use crate::walkers::IndexFieldWalker;
+use crate::{ast, ParserDatabase};
 
 /// Describes any unique criteria in a model. Can either be a primary
 /// key, or a unique index.
@@ -16,28 +14,10 @@ pub struct UniqueCriteriaWalker<'db> {
 
 impl<'db> UniqueCriteriaWalker<'db> {
     pub fn fields(self) -> impl ExactSizeIterator<Item = IndexFieldWalker<'db>> + 'db {
-        self.fields.iter().map(move |field| match field.field_location {
-            crate::types::IndexFieldLocation::InModel(field_id) => {
-                let walker = ScalarFieldWalker {
-                    model_id: self.model_id,
-                    field_id,
-                    db: self.db,
-                    scalar_field: &self.db.types.scalar_fields[&(self.model_id, field_id)],
-                };
+        let semantics = Semantics::new(self.db);
+        let model_id = self.model_id;
 
-                IndexFieldWalker::new(walker)
-            }
-            crate::types::IndexFieldLocation::InCompositeType(ctid, field_id) => {
-                let walker = CompositeTypeFieldWalker {
-                    ctid,
-                    field_id,
-                    field: &self.db.types.composite_type_fields[&(ctid, field_id)],
-                    db: self.db,
-                };
-
-                IndexFieldWalker::new(walker)
-            }
-        })
+        self.fields.iter().map(move |field| semantics.index_field(model_id, field))
     }
 
     pub fn is_strict_criteria(self) -> bool {
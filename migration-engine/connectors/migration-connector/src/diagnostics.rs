@@ -0,0 +1,18 @@
+//! The result of checking a migration for destructive or otherwise risky changes.
+
+use crate::MigrationWarning;
+
+/// The result of a [`crate::DestructiveChangeChecker`] pass over a database migration:
+/// non-fatal `warnings` a caller can choose to proceed past, and fatal `errors` that must be
+/// acknowledged (e.g. with a `force` flag) before the migration can be applied.
+#[derive(Debug, Clone, Default)]
+pub struct DestructiveChangeDiagnostics {
+    pub warnings: Vec<MigrationWarning>,
+    pub errors: Vec<String>,
+}
+
+impl DestructiveChangeDiagnostics {
+    pub fn new() -> Self {
+        DestructiveChangeDiagnostics::default()
+    }
+}
@@ -0,0 +1,25 @@
+//! The connector error type, and the result alias built on top of it.
+
+use std::fmt;
+
+/// An error produced by a [`crate::MigrationConnector`] or one of the traits it exposes.
+#[derive(Debug)]
+pub struct ConnectorError {
+    message: String,
+}
+
+impl ConnectorError {
+    pub fn from_msg(message: String) -> Self {
+        ConnectorError { message }
+    }
+}
+
+impl fmt::Display for ConnectorError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl std::error::Error for ConnectorError {}
+
+pub type ConnectorResult<T> = Result<T, ConnectorError>;
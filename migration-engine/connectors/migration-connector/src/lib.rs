@@ -0,0 +1,21 @@
+//! The API migration connectors implement, and the types the migration engine core uses to talk
+//! to them.
+
+mod diagnostics;
+mod error;
+mod migration_connector;
+pub mod migration_persistence;
+mod steps;
+
+pub use diagnostics::DestructiveChangeDiagnostics;
+pub use error::{ConnectorError, ConnectorResult};
+pub use migration_connector::{
+    DatabaseMigrationInferrer, DatabaseMigrationMarker, DatabaseMigrationStepApplier, DestructiveChangeChecker,
+    MigrationConnector,
+};
+pub use migration_persistence::{
+    select_last_non_watch_migration_sql, AppliedMigration, MigrationPersistence, MigrationPersistenceConfig,
+};
+pub use steps::{MigrationStep, MigrationWarning};
+
+pub use datamodel::Datamodel;
@@ -0,0 +1,62 @@
+//! The `MigrationConnector` trait: what the migration engine core needs from a connector for a
+//! specific database, and the smaller traits it is built out of.
+
+use crate::{ConnectorResult, DestructiveChangeDiagnostics, MigrationPersistence};
+use datamodel::Datamodel;
+
+/// A migration as inferred and represented by one particular connector.
+///
+/// This is an opaque marker: the engine core passes these around without looking inside them,
+/// only a connector's own [`DatabaseMigrationInferrer`]/[`DestructiveChangeChecker`]/
+/// [`DatabaseMigrationStepApplier`] implementations know how to produce or consume one.
+pub trait DatabaseMigrationMarker: core::fmt::Debug {}
+
+/// Infers a connector-native migration from the difference between two datamodels.
+#[async_trait::async_trait]
+pub trait DatabaseMigrationInferrer<D>: Send + Sync {
+    async fn infer(
+        &self,
+        previous: &Datamodel,
+        next: &Datamodel,
+        steps: &[crate::MigrationStep],
+    ) -> ConnectorResult<D>;
+
+    /// Infers the migration between two datamodels that are not necessarily adjacent in the
+    /// history (e.g. from the last non-watch migration to the next datamodel). The default
+    /// implementation calls [`Self::infer`] with the same arguments.
+    async fn infer_from_datamodels(
+        &self,
+        previous: &Datamodel,
+        next: &Datamodel,
+        steps: &[crate::MigrationStep],
+    ) -> ConnectorResult<D> {
+        self.infer(previous, next, steps).await
+    }
+}
+
+/// Checks a connector-native migration for destructive or otherwise risky changes.
+#[async_trait::async_trait]
+pub trait DestructiveChangeChecker<D>: Send + Sync {
+    async fn check(&self, database_migration: &D) -> ConnectorResult<DestructiveChangeDiagnostics>;
+}
+
+/// Renders a connector-native migration's steps in the connector's native, human-readable format.
+pub trait DatabaseMigrationStepApplier<D>: Send + Sync {
+    fn render_steps_pretty(&self, database_migration: &D) -> ConnectorResult<Vec<serde_json::Value>>;
+}
+
+/// What the migration engine core needs from a connector for one specific database: a way to
+/// read and write the migrations history, and a way to infer, check, and render migrations native
+/// to that database.
+pub trait MigrationConnector: Send + Sync + 'static {
+    /// The connector-native representation of a migration. Opaque to the engine core.
+    type DatabaseMigration: DatabaseMigrationMarker + Send + Sync + 'static;
+
+    fn migration_persistence(&self) -> &dyn MigrationPersistence;
+
+    fn database_migration_inferrer(&self) -> &dyn DatabaseMigrationInferrer<Self::DatabaseMigration>;
+
+    fn destructive_changes_checker(&self) -> &dyn DestructiveChangeChecker<Self::DatabaseMigration>;
+
+    fn database_migration_step_applier(&self) -> &dyn DatabaseMigrationStepApplier<Self::DatabaseMigration>;
+}
@@ -0,0 +1,76 @@
+//! Reading and writing the migrations history.
+
+use datamodel::{ast::SchemaAst, Datamodel};
+
+/// Where a [`MigrationPersistence`] implementation stores the migrations history.
+///
+/// Defaults to [`MigrationPersistenceConfig::DEFAULT_TABLE_NAME`] so existing connectors keep
+/// working unchanged. Passing a different name lets multiple Prisma projects, or a project with a
+/// pre-existing table of that name, share one database without colliding on Prisma's bookkeeping.
+#[derive(Debug, Clone)]
+pub struct MigrationPersistenceConfig {
+    table_name: String,
+}
+
+impl MigrationPersistenceConfig {
+    pub const DEFAULT_TABLE_NAME: &'static str = "_Migration";
+
+    pub fn new(table_name: impl Into<String>) -> Self {
+        MigrationPersistenceConfig {
+            table_name: table_name.into(),
+        }
+    }
+
+    pub fn table_name(&self) -> &str {
+        &self.table_name
+    }
+}
+
+impl Default for MigrationPersistenceConfig {
+    fn default() -> Self {
+        MigrationPersistenceConfig {
+            table_name: Self::DEFAULT_TABLE_NAME.to_owned(),
+        }
+    }
+}
+
+/// A previously applied, non-destructive migration, as recorded by [`MigrationPersistence`].
+pub trait AppliedMigration {
+    fn datamodel_ast(&self) -> SchemaAst;
+    fn datamodel(&self) -> Datamodel;
+}
+
+/// Reads and writes the migrations history table.
+///
+/// The table it reads from and writes to is given by [`MigrationPersistence::config`], which
+/// connectors construct from the engine configuration and default to
+/// [`MigrationPersistenceConfig::DEFAULT_TABLE_NAME`] for backward compatibility.
+#[async_trait::async_trait]
+pub trait MigrationPersistence: Send + Sync {
+    /// The persistence configuration this implementation was constructed with.
+    fn config(&self) -> &MigrationPersistenceConfig;
+
+    /// The name of the table this implementation reads from and writes to.
+    fn migrations_table_name(&self) -> &str {
+        self.config().table_name()
+    }
+
+    /// The datamodel of the last applied migration, or an empty schema if none was applied yet.
+    async fn current_datamodel_ast(&self) -> SchemaAst;
+
+    /// The last migration that was not a `watch` migration, if any.
+    async fn last_non_watch_applied_migration(&self) -> Option<Box<dyn AppliedMigration + Send + Sync>>;
+}
+
+/// The query a SQL-backed [`MigrationPersistence`] runs to implement
+/// [`MigrationPersistence::last_non_watch_applied_migration`], against whichever table
+/// [`MigrationPersistence::migrations_table_name`] resolves to.
+///
+/// Pulled out as a free function so the one place that decides "which table has the history" is
+/// this configurable name, not a literal baked into each connector's query.
+pub fn select_last_non_watch_migration_sql(persistence: &dyn MigrationPersistence) -> String {
+    format!(
+        r#"SELECT * FROM "{}" WHERE "is_watch" = false ORDER BY "started_at" DESC LIMIT 1"#,
+        persistence.migrations_table_name()
+    )
+}
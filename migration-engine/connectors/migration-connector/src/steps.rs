@@ -0,0 +1,20 @@
+//! The types that describe a migration step-by-step, independently of any one connector.
+
+use serde::{Deserialize, Serialize};
+
+/// One step of a migration, as inferred from the difference between two datamodels.
+///
+/// Connectors translate these into their own native migration steps; the migration engine core
+/// only ever deals in this connector-agnostic representation.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MigrationStep {
+    pub tag: String,
+}
+
+/// A warning about data loss or other risk caused by applying a migration.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MigrationWarning {
+    pub message: String,
+}
@@ -0,0 +1,60 @@
+//! The trait every RPC command implements, and the error type it reports through.
+
+use migration_connector::{ConnectorError, DatabaseMigrationMarker, MigrationConnector};
+use std::fmt;
+
+/// The error type every [`MigrationCommand`] reports through.
+#[derive(Debug)]
+pub struct CommandError(ConnectorError);
+
+impl fmt::Display for CommandError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl std::error::Error for CommandError {}
+
+impl From<ConnectorError> for CommandError {
+    fn from(err: ConnectorError) -> Self {
+        CommandError(err)
+    }
+}
+
+impl From<datamodel::DatamodelError> for CommandError {
+    fn from(err: datamodel::DatamodelError) -> Self {
+        CommandError(ConnectorError::from_msg(err.to_string()))
+    }
+}
+
+impl From<String> for CommandError {
+    fn from(message: String) -> Self {
+        CommandError(ConnectorError::from_msg(message))
+    }
+}
+
+pub type CommandResult<T> = Result<T, CommandError>;
+
+/// A migration engine RPC method: takes a strongly-typed input, talks to a [`MigrationEngine`]'s
+/// connector, and returns a strongly-typed output.
+///
+/// [`MigrationEngine`]: crate::migration_engine::MigrationEngine
+#[async_trait::async_trait]
+pub trait MigrationCommand {
+    type Input;
+    type Output;
+
+    async fn execute<C, D>(
+        input: &Self::Input,
+        engine: &crate::migration_engine::MigrationEngine<C, D>,
+    ) -> CommandResult<Self::Output>
+    where
+        C: MigrationConnector<DatabaseMigration = D>,
+        D: DatabaseMigrationMarker + Sync + Send + 'static;
+}
+
+/// Whether an RPC input describes a `watch` migration: a throwaway, intermediate migration used
+/// while developing a schema, as opposed to one that gets persisted to the migrations folder.
+pub trait IsWatchMigration {
+    fn is_watch_migration(&self) -> bool;
+}
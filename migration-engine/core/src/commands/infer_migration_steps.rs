@@ -30,6 +30,10 @@ impl<'a> MigrationCommand for InferMigrationStepsCommand<'a> {
         let migration_persistence = connector.migration_persistence();
         let database_migration_inferrer = connector.database_migration_inferrer();
 
+        debug!(
+            last_non_watch_migration_sql = migration_connector::select_last_non_watch_migration_sql(migration_persistence).as_str()
+        );
+
         let current_datamodel_ast = migration_persistence.current_datamodel_ast().await;
         let assumed_datamodel_ast = engine
             .datamodel_calculator()
@@ -52,6 +56,40 @@ impl<'a> MigrationCommand for InferMigrationStepsCommand<'a> {
             .check(&database_migration)
             .await?;
 
+        // The rollback (`down`) migration is the mirror image of the forward migration: it takes
+        // the database from `next` back to `assumed`. We infer it the same way, just with the
+        // datamodels swapped, so every non-watch migration we hand back can be undone. Watch migrations are
+        // throwaway intermediate states a `down` is never applied to, so skip the extra inference
+        // and destructive check for those.
+        let (rollback_steps, rollback_warnings) = if cmd.input.is_watch_migration() {
+            (Vec::new(), Vec::new())
+        } else {
+            let reverse_model_steps = engine
+                .datamodel_migration_steps_inferrer()
+                .infer(&next_datamodel_ast, &assumed_datamodel_ast);
+
+            let rollback_migration = database_migration_inferrer
+                .infer(&next_datamodel, &assumed_datamodel, &reverse_model_steps)
+                .await?;
+
+            let rollback_steps = connector
+                .database_migration_step_applier()
+                .render_steps_pretty(&rollback_migration)?;
+
+            // Reversing an additive change (e.g. dropping a column that was added) is
+            // data-destructive, even when the forward migration was not. Warn about that
+            // separately so callers don't mistake a safe `up` for a safe `down`.
+            let DestructiveChangeDiagnostics {
+                warnings: rollback_warnings,
+                errors: _,
+            } = connector
+                .destructive_changes_checker()
+                .check(&rollback_migration)
+                .await?;
+
+            (rollback_steps, rollback_warnings)
+        };
+
         let (returned_datamodel_steps, returned_database_migration) = if cmd.input.is_watch_migration() {
             let database_steps = connector
                 .database_migration_step_applier()
@@ -92,6 +130,8 @@ impl<'a> MigrationCommand for InferMigrationStepsCommand<'a> {
             errors: vec![],
             warnings,
             general_errors: vec![],
+            rollback_steps: serde_json::Value::Array(rollback_steps),
+            rollback_warnings,
         })
     }
 }
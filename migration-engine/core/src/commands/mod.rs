@@ -0,0 +1,32 @@
+//! The migration engine's RPC commands.
+
+pub mod command;
+mod infer_migration_steps;
+
+pub use command::{CommandError, CommandResult, IsWatchMigration, MigrationCommand};
+pub use infer_migration_steps::{InferMigrationStepsCommand, InferMigrationStepsInput};
+
+use migration_connector::{MigrationStep, MigrationWarning};
+use serde::Serialize;
+
+/// The output of the `InferMigrationSteps` RPC method.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MigrationStepsResultOutput {
+    pub datamodel: String,
+    pub datamodel_steps: Vec<MigrationStep>,
+    pub database_steps: serde_json::Value,
+    pub errors: Vec<String>,
+    pub warnings: Vec<MigrationWarning>,
+    pub general_errors: Vec<String>,
+    /// The steps that take the database from the input datamodel back to the assumed one (the
+    /// current datamodel plus `assume_to_be_applied`), so a caller can roll the migration back.
+    /// For a non-watch migration, `database_steps` can cover more than that (everything since the
+    /// last non-watch migration); `rollback_steps` only undoes the assumed-to-input part. Empty
+    /// for watch migrations.
+    pub rollback_steps: serde_json::Value,
+    /// Destructive-change warnings for `rollback_steps` specifically: reversing an additive
+    /// change (e.g. dropping a column that was added) can be lossy even when the forward
+    /// migration was not.
+    pub rollback_warnings: Vec<MigrationWarning>,
+}